@@ -57,3 +57,104 @@ impl<'d> BidirectionalPin for embassy_nrf::gpio::Flex<'d> {
         embassy_nrf::gpio::Flex::is_high(self)
     }
 }
+
+/// Embassy-rp implementation of BidirectionalPin for Flex pin
+#[cfg(feature = "embassy-rp")]
+impl<'d> BidirectionalPin for embassy_rp::gpio::Flex<'d> {
+    fn set_as_output(&mut self) {
+        embassy_rp::gpio::Flex::set_as_output(self);
+    }
+
+    fn set_as_input(&mut self) {
+        embassy_rp::gpio::Flex::set_pull(self, embassy_rp::gpio::Pull::None);
+        embassy_rp::gpio::Flex::set_as_input(self);
+    }
+
+    fn set_high(&mut self) {
+        embassy_rp::gpio::Flex::set_high(self);
+    }
+
+    fn set_low(&mut self) {
+        embassy_rp::gpio::Flex::set_low(self);
+    }
+
+    fn is_high(&self) -> bool {
+        embassy_rp::gpio::Flex::is_high(self)
+    }
+}
+
+/// Embassy-stm32 implementation of BidirectionalPin for Flex pin
+#[cfg(feature = "embassy-stm32")]
+impl<'d> BidirectionalPin for embassy_stm32::gpio::Flex<'d> {
+    fn set_as_output(&mut self) {
+        embassy_stm32::gpio::Flex::set_as_output(self, embassy_stm32::gpio::Speed::Medium);
+    }
+
+    fn set_as_input(&mut self) {
+        embassy_stm32::gpio::Flex::set_as_input(self, embassy_stm32::gpio::Pull::None);
+    }
+
+    fn set_high(&mut self) {
+        embassy_stm32::gpio::Flex::set_high(self);
+    }
+
+    fn set_low(&mut self) {
+        embassy_stm32::gpio::Flex::set_low(self);
+    }
+
+    fn is_high(&self) -> bool {
+        embassy_stm32::gpio::Flex::is_high(self)
+    }
+}
+
+/// esp-hal implementation of BidirectionalPin for Flex pin
+#[cfg(feature = "esp-hal")]
+impl<'d> BidirectionalPin for esp_hal::gpio::Flex<'d> {
+    fn set_as_output(&mut self) {
+        esp_hal::gpio::Flex::set_output_enable(self, true);
+    }
+
+    fn set_as_input(&mut self) {
+        esp_hal::gpio::Flex::set_output_enable(self, false);
+        esp_hal::gpio::Flex::set_input_enable(self, true);
+        esp_hal::gpio::Flex::pull_direction(self, esp_hal::gpio::Pull::None);
+    }
+
+    fn set_high(&mut self) {
+        esp_hal::gpio::Flex::set_high(self);
+    }
+
+    fn set_low(&mut self) {
+        esp_hal::gpio::Flex::set_low(self);
+    }
+
+    fn is_high(&self) -> bool {
+        esp_hal::gpio::Flex::is_high(self)
+    }
+}
+
+/// esp-hal implementation of BidirectionalPin for AnyFlex pin
+#[cfg(feature = "esp-hal")]
+impl BidirectionalPin for esp_hal::gpio::AnyFlex {
+    fn set_as_output(&mut self) {
+        esp_hal::gpio::AnyFlex::set_output_enable(self, true);
+    }
+
+    fn set_as_input(&mut self) {
+        esp_hal::gpio::AnyFlex::set_output_enable(self, false);
+        esp_hal::gpio::AnyFlex::set_input_enable(self, true);
+        esp_hal::gpio::AnyFlex::pull_direction(self, esp_hal::gpio::Pull::None);
+    }
+
+    fn set_high(&mut self) {
+        esp_hal::gpio::AnyFlex::set_high(self);
+    }
+
+    fn set_low(&mut self) {
+        esp_hal::gpio::AnyFlex::set_low(self);
+    }
+
+    fn is_high(&self) -> bool {
+        esp_hal::gpio::AnyFlex::is_high(self)
+    }
+}
@@ -5,11 +5,30 @@
 
 use defmt::Format;
 use embedded_hal::digital::OutputPin;
-use embedded_hal::spi::ErrorType;
+use embedded_hal::spi::{ErrorType, Mode, Phase, Polarity};
+use embedded_hal_async::delay::DelayNs;
 use embedded_hal_async::spi::SpiBus;
 
 use crate::bidirectional_pin::BidirectionalPin;
 
+/// Default half-period used by [`BitBangSpiBus::new_spin_loop`], in spin iterations rather
+/// than a calibrated time unit.
+const SPIN_LOOP_ITERATIONS: u32 = 32;
+
+/// Busy-wait [`DelayNs`] impl that reproduces the bus's original, uncalibrated timing.
+///
+/// It ignores the requested duration and spins a fixed number of iterations, exactly like
+/// the hardcoded delay this crate used before callers could supply their own `DelayNs`.
+pub struct SpinLoopDelay;
+
+impl DelayNs for SpinLoopDelay {
+    async fn delay_ns(&mut self, _ns: u32) {
+        for _ in 0..SPIN_LOOP_ITERATIONS {
+            core::hint::spin_loop();
+        }
+    }
+}
+
 /// Error type for bit-banging SPI
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Format)]
 pub enum BitBangError {
@@ -41,72 +60,135 @@ impl embedded_hal::spi::Error for BitBangError {
 /// let sdio = /* your bidirectional data pin */;
 /// let cs = /* your chip select pin */;
 ///
-/// let spi_bus = BitBangSpiBus::new(sck, sdio);
+/// // MODE_2 (idle-high SCK) is the closest match to the pre-`Mode` hardcoded timing.
+/// let spi_bus = BitBangSpiBus::new_spin_loop(sck, sdio, embedded_hal::spi::MODE_2);
 /// let sensor = Pmw3610::new(spi_bus, cs, None, Pmw3610Config::default());
 /// ```
-pub struct BitBangSpiBus<SCK, SDIO>
+///
+/// # Compatibility note
+///
+/// Before `Mode` support, `write_byte` drove data ahead of the leading edge while
+/// `read_byte` sampled on the trailing edge — behavior split across what `Phase` now calls
+/// `CaptureOnFirstTransition` and `CaptureOnSecondTransition`. No single `Mode` reproduces
+/// both halves of that exactly, so treat `MODE_2`/`MODE_3` above as a starting point only:
+/// re-verify timing against your PMW3610 wiring rather than assuming drop-in compatibility.
+pub struct BitBangSpiBus<SCK, SDIO, D>
 where
     SCK: OutputPin,
     SDIO: BidirectionalPin,
+    D: DelayNs,
 {
     sck: SCK,
     sdio: SDIO,
+    delay: D,
+    half_period_ns: u32,
+    mode: Mode,
 }
 
-impl<SCK, SDIO> BitBangSpiBus<SCK, SDIO>
+impl<SCK, SDIO> BitBangSpiBus<SCK, SDIO, SpinLoopDelay>
 where
     SCK: OutputPin,
     SDIO: BidirectionalPin,
 {
-    /// Create a new bit-banging SPI bus
-    pub fn new(mut sck: SCK, sdio: SDIO) -> Self {
-        let _ = sck.set_high();
-        Self { sck, sdio }
+    /// Create a new bit-banging SPI bus using a fixed busy-wait loop for timing
+    ///
+    /// This keeps the crate's original zero-dependency behavior: the effective SCK
+    /// frequency depends on the target's core clock and optimization level. Prefer
+    /// [`BitBangSpiBus::new`] with a calibrated `DelayNs` impl when the frequency matters.
+    pub fn new_spin_loop(sck: SCK, sdio: SDIO, mode: Mode) -> Self {
+        Self::new(sck, sdio, SpinLoopDelay, 0, mode)
     }
+}
 
-    #[inline(always)]
-    fn spi_delay() {
-        // Short busy-wait delay for SPI timing
-        // This is approximately 32 cycles at typical clock speeds
-        for _ in 0..32 {
-            core::hint::spin_loop();
+impl<SCK, SDIO, D> BitBangSpiBus<SCK, SDIO, D>
+where
+    SCK: OutputPin,
+    SDIO: BidirectionalPin,
+    D: DelayNs,
+{
+    /// Create a new bit-banging SPI bus
+    ///
+    /// `half_period_ns` is the delay held after each SCK edge. Reads wait it twice per bit,
+    /// so their SCK period is approximately `2 * half_period_ns`; writes also wait it once
+    /// more before each bit's leading edge, so their period is approximately
+    /// `3 * half_period_ns`. Pass a
+    /// `delay` calibrated to the target's clock to get a predictable bus speed. `mode`
+    /// selects the SCK idle level (`Polarity`) and which edge of each bit period drives
+    /// and samples SDIO (`Phase`), matching the target's datasheet timing.
+    pub fn new(mut sck: SCK, sdio: SDIO, delay: D, half_period_ns: u32, mode: Mode) -> Self {
+        Self::drive_idle(&mut sck, mode.polarity);
+        Self {
+            sck,
+            sdio,
+            delay,
+            half_period_ns,
+            mode,
         }
     }
 
+    fn drive_idle(sck: &mut SCK, polarity: Polarity) {
+        let _ = match polarity {
+            Polarity::IdleLow => sck.set_low(),
+            Polarity::IdleHigh => sck.set_high(),
+        };
+    }
+
+    fn drive_active(sck: &mut SCK, polarity: Polarity) {
+        let _ = match polarity {
+            Polarity::IdleLow => sck.set_high(),
+            Polarity::IdleHigh => sck.set_low(),
+        };
+    }
+
     /// Write a single byte over the bidirectional SPI (MSB first)
-    fn write_byte(&mut self, byte: u8) {
+    async fn write_byte(&mut self, byte: u8) {
         self.sdio.set_as_output();
 
         for i in (0..8).rev() {
-            if (byte >> i) & 1 == 1 {
-                self.sdio.set_high();
-            } else {
-                self.sdio.set_low();
+            let bit_high = (byte >> i) & 1 == 1;
+            let set_bit = |sdio: &mut SDIO| {
+                if bit_high {
+                    sdio.set_high();
+                } else {
+                    sdio.set_low();
+                }
+            };
+
+            if self.mode.phase == Phase::CaptureOnFirstTransition {
+                set_bit(&mut self.sdio);
             }
-            Self::spi_delay();
+            self.delay.delay_ns(self.half_period_ns).await;
+
+            Self::drive_active(&mut self.sck, self.mode.polarity);
+            self.delay.delay_ns(self.half_period_ns).await;
 
-            let _ = self.sck.set_low();
-            Self::spi_delay();
+            if self.mode.phase == Phase::CaptureOnSecondTransition {
+                set_bit(&mut self.sdio);
+            }
 
-            let _ = self.sck.set_high();
-            Self::spi_delay();
+            Self::drive_idle(&mut self.sck, self.mode.polarity);
+            self.delay.delay_ns(self.half_period_ns).await;
         }
     }
 
     /// Read a single byte from the bidirectional SPI (MSB first)
-    fn read_byte(&mut self) -> u8 {
+    async fn read_byte(&mut self) -> u8 {
         self.sdio.set_as_input();
 
         let mut byte = 0u8;
 
         for i in (0..8).rev() {
-            let _ = self.sck.set_low();
-            Self::spi_delay();
+            Self::drive_active(&mut self.sck, self.mode.polarity);
+            self.delay.delay_ns(self.half_period_ns).await;
+
+            if self.mode.phase == Phase::CaptureOnFirstTransition && self.sdio.is_high() {
+                byte |= 1 << i;
+            }
 
-            let _ = self.sck.set_high();
-            Self::spi_delay();
+            Self::drive_idle(&mut self.sck, self.mode.polarity);
+            self.delay.delay_ns(self.half_period_ns).await;
 
-            if self.sdio.is_high() {
+            if self.mode.phase == Phase::CaptureOnSecondTransition && self.sdio.is_high() {
                 byte |= 1 << i;
             }
         }
@@ -115,29 +197,31 @@ where
     }
 }
 
-impl<SCK, SDIO> ErrorType for BitBangSpiBus<SCK, SDIO>
+impl<SCK, SDIO, D> ErrorType for BitBangSpiBus<SCK, SDIO, D>
 where
     SCK: OutputPin,
     SDIO: BidirectionalPin,
+    D: DelayNs,
 {
     type Error = BitBangError;
 }
 
-impl<SCK, SDIO> SpiBus for BitBangSpiBus<SCK, SDIO>
+impl<SCK, SDIO, D> SpiBus for BitBangSpiBus<SCK, SDIO, D>
 where
     SCK: OutputPin,
     SDIO: BidirectionalPin,
+    D: DelayNs,
 {
     async fn read(&mut self, words: &mut [u8]) -> Result<(), Self::Error> {
         for word in words.iter_mut() {
-            *word = self.read_byte();
+            *word = self.read_byte().await;
         }
         Ok(())
     }
 
     async fn write(&mut self, words: &[u8]) -> Result<(), Self::Error> {
         for &word in words {
-            self.write_byte(word);
+            self.write_byte(word).await;
         }
         Ok(())
     }
@@ -152,8 +236,8 @@ where
     async fn transfer_in_place(&mut self, words: &mut [u8]) -> Result<(), Self::Error> {
         // For half-duplex: read replaces written data
         for word in words.iter_mut() {
-            self.write_byte(*word);
-            *word = self.read_byte();
+            self.write_byte(*word).await;
+            *word = self.read_byte().await;
         }
         Ok(())
     }
@@ -0,0 +1,135 @@
+// Bit-banging SPI Device Implementation
+//
+// This module folds chip-select timing into the bit-banging SPI abstraction, following the
+// same pattern embassy HALs use to pair a bus with its CS pin. It's designed for sensors
+// like PMW3610 that have strict per-transaction CS-to-clock and CS-hold delays.
+
+use embedded_hal::digital::OutputPin;
+use embedded_hal::spi::ErrorType;
+use embedded_hal_async::delay::DelayNs;
+use embedded_hal_async::spi::{Operation, SpiBus, SpiDevice};
+
+use crate::bidirectional_pin::BidirectionalPin;
+use crate::bitbang_spi::{BitBangError, BitBangSpiBus};
+
+/// Chip-select timing for a [`BitBangSpiDevice`]
+///
+/// All delays default to zero; set them to match the target sensor's datasheet timing.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BitBangSpiDeviceConfig {
+    /// Delay between asserting CS and the first clock edge
+    pub cs_setup_ns: u32,
+    /// Delay between the last clock edge and deasserting CS
+    pub cs_hold_ns: u32,
+    /// Delay inserted between consecutive operations within a transaction
+    pub inter_op_ns: u32,
+}
+
+/// Bit-banging SPI device that owns chip-select timing
+///
+/// This wraps a [`BitBangSpiBus`] plus a `CS` output pin and implements
+/// `embedded_hal_async::spi::SpiDevice`, driving CS low with a configurable setup delay
+/// before the operation group, running the transaction, then raising CS after a
+/// configurable hold delay. This spares callers from hand-rolling CS logic around a
+/// generic `ExclusiveDevice`.
+///
+/// # Type Parameters
+/// - `SCK`, `SDIO`, `BD`: see [`BitBangSpiBus`]
+/// - `CS`: chip-select pin (output)
+/// - `D`: delay used for the CS setup/hold/inter-op timing
+pub struct BitBangSpiDevice<SCK, SDIO, CS, D, BD>
+where
+    SCK: OutputPin,
+    SDIO: BidirectionalPin,
+    CS: OutputPin,
+    D: DelayNs,
+    BD: DelayNs,
+{
+    bus: BitBangSpiBus<SCK, SDIO, BD>,
+    cs: CS,
+    delay: D,
+    config: BitBangSpiDeviceConfig,
+}
+
+impl<SCK, SDIO, CS, D, BD> BitBangSpiDevice<SCK, SDIO, CS, D, BD>
+where
+    SCK: OutputPin,
+    SDIO: BidirectionalPin,
+    CS: OutputPin,
+    D: DelayNs,
+    BD: DelayNs,
+{
+    /// Create a new bit-banging SPI device, deasserting `cs` immediately
+    pub fn new(
+        bus: BitBangSpiBus<SCK, SDIO, BD>,
+        mut cs: CS,
+        delay: D,
+        config: BitBangSpiDeviceConfig,
+    ) -> Self {
+        let _ = cs.set_high();
+        Self {
+            bus,
+            cs,
+            delay,
+            config,
+        }
+    }
+
+    /// Run `operations` with CS already asserted, without touching CS on the way out
+    ///
+    /// Keeping this separate from [`SpiDevice::transaction`] lets the caller always
+    /// deassert CS, even when an operation fails partway through.
+    async fn run_operations(
+        &mut self,
+        operations: &mut [Operation<'_, u8>],
+    ) -> Result<(), BitBangError> {
+        let last = operations.len().saturating_sub(1);
+        for (i, op) in operations.iter_mut().enumerate() {
+            match op {
+                Operation::Read(buf) => self.bus.read(buf).await?,
+                Operation::Write(buf) => self.bus.write(buf).await?,
+                Operation::Transfer(read, write) => self.bus.transfer(read, write).await?,
+                Operation::TransferInPlace(buf) => self.bus.transfer_in_place(buf).await?,
+                Operation::DelayNs(ns) => self.delay.delay_ns(*ns).await,
+            }
+            if i != last {
+                self.delay.delay_ns(self.config.inter_op_ns).await;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<SCK, SDIO, CS, D, BD> ErrorType for BitBangSpiDevice<SCK, SDIO, CS, D, BD>
+where
+    SCK: OutputPin,
+    SDIO: BidirectionalPin,
+    CS: OutputPin,
+    D: DelayNs,
+    BD: DelayNs,
+{
+    type Error = BitBangError;
+}
+
+impl<SCK, SDIO, CS, D, BD> SpiDevice for BitBangSpiDevice<SCK, SDIO, CS, D, BD>
+where
+    SCK: OutputPin,
+    SDIO: BidirectionalPin,
+    CS: OutputPin,
+    D: DelayNs,
+    BD: DelayNs,
+{
+    async fn transaction(
+        &mut self,
+        operations: &mut [Operation<'_, u8>],
+    ) -> Result<(), Self::Error> {
+        let _ = self.cs.set_low();
+        self.delay.delay_ns(self.config.cs_setup_ns).await;
+
+        let result = self.run_operations(operations).await;
+
+        self.delay.delay_ns(self.config.cs_hold_ns).await;
+        let _ = self.cs.set_high();
+        result
+    }
+}
@@ -0,0 +1,250 @@
+// PIO-backed half-duplex SPI Bus Implementation (RP2040)
+//
+// This module offloads the clock/data shifting used by `BitBangSpiBus` to a PIO state
+// machine on RP2040, giving megahertz-class, deterministic timing without burning the CPU
+// in a busy-wait loop. It implements the same `embedded_hal_async::spi::SpiBus` surface so
+// it's a drop-in alternative wherever `BitBangSpiBus` is used today.
+
+#![cfg(feature = "embassy-rp")]
+
+use defmt::Format;
+use embassy_futures::yield_now;
+use embassy_rp::pio::{
+    Common, Config, Direction, Instance, Pin as PioPinHandle, PioPin, ShiftDirection,
+    StateMachine,
+};
+use embedded_hal::spi::ErrorType;
+use embedded_hal_async::delay::DelayNs;
+use embedded_hal_async::spi::SpiBus;
+use fixed::types::extra::U8;
+use fixed::FixedU32;
+use pio::pio_asm;
+
+/// Error type for the PIO-backed half-duplex SPI bus
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Format)]
+pub enum PioSpiError {
+    /// Generic SPI error (placeholder for compatibility)
+    Bus,
+}
+
+impl embedded_hal::spi::Error for PioSpiError {
+    fn kind(&self) -> embedded_hal::spi::ErrorKind {
+        embedded_hal::spi::ErrorKind::Other
+    }
+}
+
+/// PIO program that shifts SDIO out, MSB first, for the write half of a transfer
+///
+/// Autopull (threshold 8, see [`PioSpiBus::new`]) reloads the OSR from the TX FIFO every
+/// byte and stalls the state machine when the FIFO runs dry, so DMA paces the transfer
+/// rather than a manual loop counter.
+fn write_program() -> pio::Program<32> {
+    pio_asm!(
+        ".side_set 1"
+        ".wrap_target"
+        "    out pins, 1    side 0"
+        "    nop            side 1"
+        ".wrap"
+    )
+    .program
+}
+
+/// PIO program that shifts SDIO in, MSB first, for the read half of a transfer
+///
+/// Autopush (threshold 8) flushes the ISR to the RX FIFO every byte, again let DMA pace
+/// the transfer instead of a manual loop counter.
+fn read_program() -> pio::Program<32> {
+    pio_asm!(
+        ".side_set 1"
+        ".wrap_target"
+        "    nop            side 0"
+        "    in pins, 1     side 1"
+        ".wrap"
+    )
+    .program
+}
+
+/// PIO-backed half-duplex SPI bus for RP2040
+///
+/// This implements the `embedded_hal_async::spi::SpiBus` trait by driving SCK and a
+/// bidirectional SDIO pin from a PIO state machine instead of bit-banging GPIOs from the
+/// CPU. Both programs are loaded once in [`PioSpiBus::new`]. `write` applies the write
+/// program's config and switches SDIO to an output before shifting the TX FIFO out
+/// MSB-first, then waits for the TX FIFO and OSR to actually drain; `read` applies the
+/// read program's config and switches SDIO to an input before shifting the RX FIFO in,
+/// with a DMA channel carrying each direction so the async methods can await completion
+/// instead of spinning. The drain wait matters because DMA completion only confirms the
+/// bytes reached the TX FIFO, not that the state machine finished shifting the last byte's
+/// bits onto the wire — flipping SDIO's direction before that happens would truncate the
+/// write.
+///
+/// # Type Parameters
+/// - `'d`: lifetime of the borrowed PIO/DMA peripherals
+/// - `P`: the PIO instance (`PIO0` or `PIO1`)
+/// - `SM`: the state machine index within `P`
+/// - `D`: delay used to cover the last byte's shift-out time after the TX FIFO drains
+pub struct PioSpiBus<'d, P, const SM: usize, D>
+where
+    P: Instance,
+    D: DelayNs,
+{
+    sm: StateMachine<'d, P, SM>,
+    sdio: PioPinHandle<'d, P>,
+    write_cfg: Config<'d, P>,
+    read_cfg: Config<'d, P>,
+    tx_dma: embassy_rp::dma::Channel<'d>,
+    rx_dma: embassy_rp::dma::Channel<'d>,
+    delay: D,
+    byte_period_ns: u32,
+}
+
+impl<'d, P, const SM: usize, D> PioSpiBus<'d, P, SM, D>
+where
+    P: Instance,
+    D: DelayNs,
+{
+    /// Create a new PIO-backed half-duplex SPI bus
+    ///
+    /// `clock_divider` sets the PIO clock divider (see [`FixedU32`]) that determines the
+    /// resulting SCK frequency relative to the system clock. `byte_period_ns` must cover
+    /// the time to shift one full byte out at that rate (i.e. roughly `8 / sck_frequency`
+    /// in nanoseconds); it's used after a write's TX FIFO empties to make sure the state
+    /// machine has actually finished shifting the last byte's bits onto SCK/SDIO before
+    /// `read` reconfigures the state machine and flips SDIO's direction.
+    pub fn new(
+        common: &mut Common<'d, P>,
+        mut sm: StateMachine<'d, P, SM>,
+        sck_pin: impl PioPin,
+        sdio_pin: impl PioPin,
+        tx_dma: embassy_rp::dma::Channel<'d>,
+        rx_dma: embassy_rp::dma::Channel<'d>,
+        clock_divider: FixedU32<U8>,
+        delay: D,
+        byte_period_ns: u32,
+    ) -> Self {
+        let sck = common.make_pio_pin(sck_pin);
+        let sdio = common.make_pio_pin(sdio_pin);
+
+        let write_program = common.load_program(&write_program());
+        let read_program = common.load_program(&read_program());
+
+        let mut write_cfg = Config::default();
+        write_cfg.use_program(&write_program, &[&sck]);
+        write_cfg.set_out_pins(&[&sdio]);
+        write_cfg.shift_out.direction = ShiftDirection::Left;
+        write_cfg.shift_out.auto_fill = true;
+        write_cfg.shift_out.threshold = 8;
+        write_cfg.clock_divider = clock_divider;
+
+        let mut read_cfg = Config::default();
+        read_cfg.use_program(&read_program, &[&sck]);
+        read_cfg.set_in_pins(&[&sdio]);
+        read_cfg.shift_in.direction = ShiftDirection::Left;
+        read_cfg.shift_in.auto_fill = true;
+        read_cfg.shift_in.threshold = 8;
+        read_cfg.clock_divider = clock_divider;
+
+        sm.set_pin_dirs(Direction::Out, &[&sck]);
+        sm.set_enable(true);
+
+        Self {
+            sm,
+            sdio,
+            write_cfg,
+            read_cfg,
+            tx_dma,
+            rx_dma,
+            delay,
+            byte_period_ns,
+        }
+    }
+
+    /// Switch the state machine (and SDIO's pin direction) to drive the bus for a write
+    fn enter_write_mode(&mut self) {
+        self.sm.set_config(&self.write_cfg);
+        self.sm.set_pin_dirs(Direction::Out, &[&self.sdio]);
+    }
+
+    /// Switch the state machine (and SDIO's pin direction) to sample the bus for a read
+    fn enter_read_mode(&mut self) {
+        self.sm.set_config(&self.read_cfg);
+        self.sm.set_pin_dirs(Direction::In, &[&self.sdio]);
+    }
+
+    /// Wait for the TX FIFO and OSR to finish draining after a write
+    ///
+    /// `dma_push` completing only means DMA copied the bytes into the TX FIFO register,
+    /// not that the state machine finished shifting the last byte's bits out. Poll the
+    /// FIFO empty flag, then hold for one more byte period so the OSR's last bits are
+    /// actually on the wire before the caller reconfigures the state machine.
+    async fn drain_write(&mut self) {
+        while !self.sm.tx().empty() {
+            yield_now().await;
+        }
+        self.delay.delay_ns(self.byte_period_ns).await;
+    }
+
+    async fn write_bytes(&mut self, words: &[u8]) {
+        self.enter_write_mode();
+        self.sm
+            .tx()
+            .dma_push(self.tx_dma.reborrow(), words, false)
+            .await;
+        self.drain_write().await;
+    }
+
+    async fn read_bytes(&mut self, words: &mut [u8]) {
+        self.enter_read_mode();
+        self.sm
+            .rx()
+            .dma_pull(self.rx_dma.reborrow(), words, false)
+            .await;
+    }
+}
+
+impl<'d, P, const SM: usize, D> ErrorType for PioSpiBus<'d, P, SM, D>
+where
+    P: Instance,
+    D: DelayNs,
+{
+    type Error = PioSpiError;
+}
+
+impl<'d, P, const SM: usize, D> SpiBus for PioSpiBus<'d, P, SM, D>
+where
+    P: Instance,
+    D: DelayNs,
+{
+    async fn read(&mut self, words: &mut [u8]) -> Result<(), Self::Error> {
+        self.read_bytes(words).await;
+        Ok(())
+    }
+
+    async fn write(&mut self, words: &[u8]) -> Result<(), Self::Error> {
+        self.write_bytes(words).await;
+        Ok(())
+    }
+
+    async fn transfer(&mut self, read: &mut [u8], write: &[u8]) -> Result<(), Self::Error> {
+        // For half-duplex: write first, then read
+        self.write_bytes(write).await;
+        self.read_bytes(read).await;
+        Ok(())
+    }
+
+    async fn transfer_in_place(&mut self, words: &mut [u8]) -> Result<(), Self::Error> {
+        // For half-duplex: read replaces written data, one byte at a time so the same
+        // buffer can serve as both the write source and the read destination
+        for word in words.iter_mut() {
+            self.write_bytes(core::slice::from_ref(word)).await;
+            let mut byte = [0u8; 1];
+            self.read_bytes(&mut byte).await;
+            *word = byte[0];
+        }
+        Ok(())
+    }
+
+    async fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}